@@ -0,0 +1,130 @@
+use multiversx_sc::types::{
+    BigUint, EsdtTokenPayment, ManagedVec, MultiValueEncoded, TokenIdentifier,
+};
+use multiversx_sc_scenario::api::StaticApi;
+use multiversx_sc_scenario::imports::{MxscPath, TestAddress, TestSCAddress};
+use multiversx_sc_scenario::ScenarioWorld;
+
+use data_store::{DataStore, DayOfWeek, EnumWithEverything, TOKEN_IDENTIFIER, TOKEN_IDENTIFIER_2};
+
+const DATA_STORE_PATH: MxscPath = MxscPath::new("output/data-store.mxsc.json");
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const DATA_STORE_ADDRESS: TestSCAddress = TestSCAddress::new("data-store");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.register_contract(DATA_STORE_PATH, data_store::ContractBuilder);
+    blockchain
+}
+
+/// Deploy the contract through a whitebox `init`, with the exact params it expects.
+fn deploy() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER_ADDRESS).nonce(1);
+    world
+        .account(DATA_STORE_ADDRESS)
+        .nonce(1)
+        .code(DATA_STORE_PATH)
+        .owner(OWNER_ADDRESS);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(DATA_STORE_ADDRESS)
+        .whitebox(data_store::contract_obj, |sc| {
+            sc.init(EnumWithEverything::Default, 123455u32, -3i8);
+        });
+
+    world
+}
+
+#[test]
+fn test_3_whitebox() {
+    let mut world = deploy();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(DATA_STORE_ADDRESS)
+        .whitebox(data_store::contract_obj, |sc| {
+            let mut biguints = MultiValueEncoded::new();
+            for i in 0..4u64 {
+                biguints.push(BigUint::from(i));
+            }
+            sc.test_3(-3isize, biguints);
+
+            // decoded storage assertions
+            assert_eq!(sc.my_isize().get(), -3isize);
+            assert_eq!(sc.my_vec_biguint().len(), 4);
+            assert_eq!(sc.my_vec_biguint().get(4), BigUint::from(3u64));
+        });
+}
+
+#[test]
+fn view_test_1_whitebox() {
+    let mut world = deploy();
+
+    world
+        .query()
+        .to(DATA_STORE_ADDRESS)
+        .whitebox(data_store::contract_obj, |sc| {
+            let mut e_seq = ManagedVec::new();
+            e_seq.push(1u8);
+            e_seq.push(2u8);
+            e_seq.push(4u8);
+            e_seq.push(8u8);
+
+            let mut f_seq = ManagedVec::new();
+            f_seq.push(9u8);
+            f_seq.push(45u8);
+
+            let result = sc
+                .view_test_1(
+                    DayOfWeek::Monday,
+                    DayOfWeek::Sunday,
+                    EnumWithEverything::Default,
+                    EnumWithEverything::Today(DayOfWeek::Tuesday),
+                    EnumWithEverything::Write(e_seq, 14u16),
+                    EnumWithEverything::Struct {
+                        int: 8u16,
+                        seq: f_seq,
+                        another_byte: 0u8,
+                        uint_32: 789484u32,
+                        uint_64: 485u64,
+                    },
+                )
+                .into_tuple();
+
+            assert_eq!(result.0, DayOfWeek::Monday);
+            assert_eq!(result.1, DayOfWeek::Sunday);
+            assert_eq!(result.2, EnumWithEverything::Default);
+        });
+}
+
+#[test]
+fn view_test_2_whitebox() {
+    let mut world = deploy();
+
+    world
+        .query()
+        .to(DATA_STORE_ADDRESS)
+        .whitebox(data_store::contract_obj, |sc| {
+            let mut payments: MultiValueEncoded<StaticApi, EsdtTokenPayment<StaticApi>> =
+                MultiValueEncoded::new();
+            payments.push(EsdtTokenPayment::new(
+                TokenIdentifier::from(TOKEN_IDENTIFIER),
+                0,
+                BigUint::from(89784651u64),
+            ));
+            payments.push(EsdtTokenPayment::new(
+                TokenIdentifier::from(TOKEN_IDENTIFIER_2),
+                0,
+                BigUint::from(184791484u64),
+            ));
+
+            let returned = sc.view_test_2(payments).to_vec();
+            assert_eq!(returned.len(), 2);
+            assert_eq!(returned.get(0).amount, BigUint::from(89784651u64));
+            assert_eq!(returned.get(1).amount, BigUint::from(184791484u64));
+        });
+}
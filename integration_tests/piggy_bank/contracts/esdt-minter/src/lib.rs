@@ -1,11 +1,27 @@
 #![no_std]
 
+mod pause_module;
+
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+/// One hundred percent expressed in basis points.
+const BPS_DENOMINATOR: u64 = 10_000;
+/// Upper bound on the configurable interest rate (1000%), rejecting absurd values.
+const MAX_INTEREST_BPS: u64 = 100_000;
+/// Default gas floor below which `process_airdrops` persists its cursor and returns.
+const DEFAULT_MIN_GAS: u64 = 5_000_000;
+
+/// Signal returned by the body of a resumable loop.
+enum LoopOp {
+    Continue,
+    Break,
+}
+
 #[multiversx_sc::contract]
 pub trait EsdtMinter:
     multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + pause_module::PauseModule
 {
     // #################   storage    #################
 
@@ -19,11 +35,65 @@ pub trait EsdtMinter:
     #[storage_mapper("airdop_amount")]
     fn airdrop_amount(&self, address: ManagedAddress) -> SingleValueMapper<BigUint>;
 
-    /// Percentage of token to distribute when interests are claimed
-    /// Ex: 12 -> 12%
-    #[view(getInterestPercentage)]
-    #[storage_mapper("interest_percentage")]
-    fn interest_percentage(&self) -> SingleValueMapper<u64>;
+    /// Number of decimals of the issued token, captured at `issue_token` time.
+    /// Used to interpret whole-token limits as raw units.
+    #[view(getNumDecimals)]
+    #[storage_mapper("num_decimals")]
+    fn num_decimals(&self) -> SingleValueMapper<usize>;
+
+    /// Maximum amount claimable per `claim_airdrop` call, expressed in whole tokens.
+    /// A value of `0` disables the limit.
+    #[view(getAirdropLimit)]
+    #[storage_mapper("airdrop_limit")]
+    fn airdrop_limit(&self) -> SingleValueMapper<BigUint>;
+
+    /// Minimum number of blocks a user must wait between two airdrop claims
+    #[view(getAirdropCooldown)]
+    #[storage_mapper("airdrop_cooldown")]
+    fn airdrop_cooldown(&self) -> SingleValueMapper<u64>;
+
+    /// Block nonce of the last airdrop claim per address
+    #[view(getLastClaimBlock)]
+    #[storage_mapper("last_claim_block")]
+    fn last_claim_block(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Queue of (address, amount) airdrops waiting to be distributed by `process_airdrops`
+    #[storage_mapper("pending_airdrops")]
+    fn pending_airdrops(&self) -> VecMapper<(ManagedAddress, BigUint)>;
+
+    /// Index of the next queued airdrop to process. Persisted when a `process_airdrops`
+    /// call runs low on gas, cleared once the queue is exhausted.
+    #[view(getAirdropCursor)]
+    #[storage_mapper("airdrop_cursor")]
+    fn airdrop_cursor(&self) -> SingleValueMapper<usize>;
+
+    /// Gas floor below which `process_airdrops` stops and persists its cursor
+    #[view(getMinGas)]
+    #[storage_mapper("min_gas")]
+    fn min_gas(&self) -> SingleValueMapper<u64>;
+
+    /// Maximum airdrop allocation per address, expressed in whole tokens.
+    /// A value of `0` disables the cap.
+    #[view(getMaxAirdropPerAddress)]
+    #[storage_mapper("max_airdrop_per_address")]
+    fn max_airdrop_per_address(&self) -> SingleValueMapper<BigUint>;
+
+    /// Maximum total airdrop ever distributed, expressed in whole tokens.
+    /// A value of `0` disables the cap.
+    #[view(getGlobalAirdropCap)]
+    #[storage_mapper("global_airdrop_cap")]
+    fn global_airdrop_cap(&self) -> SingleValueMapper<BigUint>;
+
+    /// Running total of airdrop raw units already minted and sent
+    #[view(getTotalDistributed)]
+    #[storage_mapper("total_distributed")]
+    fn total_distributed(&self) -> SingleValueMapper<BigUint>;
+
+    /// Interest rate in basis points (1 bp = 1/10000) distributed when interests
+    /// are claimed. Ex: 250 -> 2.5%
+    #[view(getInterestBps)]
+    #[storage_mapper("interest_bps")]
+    fn interest_bps(&self) -> SingleValueMapper<u64>;
 
     /// Whitelist containing the addresses allowed to call the interests endpoint
     ///
@@ -32,10 +102,20 @@ pub trait EsdtMinter:
 
     // #################   views    #################
 
+    /// Amount the given address could claim on its next successful call,
+    /// i.e. `min(airdrop_balance, per_claim_limit, remaining_global_cap)`.
+    #[view(getRemainingClaimable)]
+    fn remaining_claimable(&self, address: ManagedAddress) -> BigUint {
+        let claimable = self.airdrop_amount(address).get();
+        self.clamp_to_global_cap(self.cap_to_per_claim_limit(claimable))
+    }
+
     // #################   init && upgrade    #################
     #[init]
-    fn init(&self, interest_percentage: u64) {
-        self.interest_percentage().set(interest_percentage);
+    fn init(&self, interest_bps: u64) {
+        require!(interest_bps <= MAX_INTEREST_BPS, "Interest rate too high");
+        self.interest_bps().set(interest_bps);
+        self.min_gas().set_if_empty(DEFAULT_MIN_GAS);
     }
 
     #[upgrade]
@@ -51,12 +131,32 @@ pub trait EsdtMinter:
     ///
     #[endpoint(claimAirdrop)]
     fn claim_airdrop(&self) {
+        self.require_not_paused();
         let caller = self.blockchain().get_caller();
         let claimable_amount = self.airdrop_amount(caller.clone()).get();
         require!(&claimable_amount > &BigUint::zero(), "Nothing to claim");
+
+        // enforce the per-address cooldown window
+        let current_block = self.blockchain().get_block_nonce();
+        let cooldown = self.airdrop_cooldown().get();
+        require!(
+            current_block >= self.last_claim_block(&caller).get() + cooldown,
+            "Airdrop cooldown not elapsed"
+        );
+
+        // mint at most the faucet limit, then clamp to the remaining global cap,
+        // leaving any remainder for a later claim
+        let claimed_amount =
+            self.clamp_to_global_cap(self.cap_to_per_claim_limit(claimable_amount));
+        require!(&claimed_amount > &BigUint::zero(), "Global airdrop cap reached");
+
         self.esdt_identifier()
-            .mint_and_send(&caller, claimable_amount);
-        self.airdrop_amount(caller).clear();
+            .mint_and_send(&caller, claimed_amount.clone());
+
+        self.airdrop_amount(caller.clone())
+            .update(|val| *val -= &claimed_amount);
+        self.total_distributed().update(|val| *val += &claimed_amount);
+        self.last_claim_block(&caller).set(current_block);
     }
 
     // #################   restricted endpoints    #################
@@ -83,8 +183,11 @@ pub trait EsdtMinter:
         self.interest_whitelist().require_whitelisted(&caller);
         self.require_good_token_identifier(&capital_payment);
 
-        // mint the interests
-        let interest_amount = &capital_payment.amount * self.interest_percentage().get() / 100u64;
+        // mint the interests, rounding the remainder half-up to avoid truncating
+        // small deposits to zero interest
+        let interest_amount = (&capital_payment.amount * self.interest_bps().get()
+            + BPS_DENOMINATOR / 2)
+            / BPS_DENOMINATOR;
         self.esdt_identifier().mint(interest_amount.clone());
 
         // send back the capital along the the interests
@@ -104,6 +207,32 @@ pub trait EsdtMinter:
         payment_with_interests
     }
 
+    /// INTEREST WHITELIST RESTRICTED
+    ///
+    /// Mint a caller-specified interest amount and send it to the caller. Used by
+    /// the piggy-bank, which computes the interest locally and only relies on the
+    /// minter to fund the newly minted tokens.
+    ///
+    /// ### Arguments
+    ///
+    /// * **amount** - `BigUint` interest amount to mint and send
+    ///
+    /// ### Return Payments:
+    ///
+    /// * **interest_payment**: freshly minted interest for the caller
+    ///
+    #[endpoint(fundInterest)]
+    fn fund_interest(&self, amount: BigUint) -> EsdtTokenPayment<Self::Api> {
+        let caller = self.blockchain().get_caller();
+        self.interest_whitelist().require_whitelisted(&caller);
+
+        self.esdt_identifier().mint(amount.clone());
+        let token_identifier = self.esdt_identifier().get_token_id();
+        self.send().direct_esdt(&caller, &token_identifier, 0u64, &amount);
+
+        EsdtTokenPayment::new(token_identifier, 0u64, amount)
+    }
+
     /// OWNER RESTRICTED
     ///
     /// Add an address to the interest whitelist
@@ -156,6 +285,7 @@ pub trait EsdtMinter:
         num_decimals: usize,
     ) {
         let register_cost = &*self.call_value().egld_value();
+        self.num_decimals().set(num_decimals);
         self.esdt_identifier().issue_and_set_all_roles(
             register_cost.clone(),
             token_display_name,
@@ -165,6 +295,36 @@ pub trait EsdtMinter:
         );
     }
 
+    /// OWNER RESTRICTED
+    ///
+    /// Set the per-claim faucet limit expressed in whole tokens. The limit is
+    /// internally scaled by the token `num_decimals`, so `500` means `500 * 10^num_decimals`
+    /// raw units. A value of `0` disables the limit.
+    ///
+    /// ### Arguments
+    ///
+    /// * **whole_tokens** - `BigUint` Per-claim limit, in display units
+    ///
+    #[only_owner]
+    #[endpoint(setAirdropLimit)]
+    fn set_airdrop_limit(&self, whole_tokens: BigUint) {
+        self.airdrop_limit().set(whole_tokens);
+    }
+
+    /// OWNER RESTRICTED
+    ///
+    /// Set the number of blocks a user must wait between two airdrop claims.
+    ///
+    /// ### Arguments
+    ///
+    /// * **blocks** - `u64` Cooldown window in blocks
+    ///
+    #[only_owner]
+    #[endpoint(setAirdropCooldown)]
+    fn set_airdrop_cooldown(&self, blocks: u64) {
+        self.airdrop_cooldown().set(blocks);
+    }
+
     /// OWNER RESTRICTED
     ///
     /// Add some amount to a user airdrop balance
@@ -177,11 +337,172 @@ pub trait EsdtMinter:
     #[only_owner]
     #[endpoint(addAirdropAmount)]
     fn add_airdrop_amount(&self, address: ManagedAddress, amount: BigUint) {
-        self.airdrop_amount(address).update(|val| *val += amount);
+        self.airdrop_amount(address).update(|val| {
+            *val += amount;
+            let max_whole = self.max_airdrop_per_address().get();
+            if max_whole > 0u64 {
+                require!(
+                    *val <= self.to_raw_units(&max_whole),
+                    "Exceeds max airdrop per address"
+                );
+            }
+        });
+    }
+
+    /// OWNER RESTRICTED
+    ///
+    /// Set the maximum airdrop allocation per address, in whole tokens (`0` disables it).
+    #[only_owner]
+    #[endpoint(setMaxAirdropPerAddress)]
+    fn set_max_airdrop_per_address(&self, whole_tokens: BigUint) {
+        self.max_airdrop_per_address().set(whole_tokens);
+    }
+
+    /// OWNER RESTRICTED
+    ///
+    /// Set the global airdrop cap, in whole tokens (`0` disables it).
+    #[only_owner]
+    #[endpoint(setGlobalAirdropCap)]
+    fn set_global_airdrop_cap(&self, whole_tokens: BigUint) {
+        self.global_airdrop_cap().set(whole_tokens);
+    }
+
+    /// OWNER RESTRICTED
+    ///
+    /// Queue a batch of airdrops to be distributed later by `process_airdrops`.
+    ///
+    /// ### Arguments
+    ///
+    /// * **airdrops** - list of `(address, amount)` pairs to mint and send
+    ///
+    #[only_owner]
+    #[endpoint(queueAirdrops)]
+    fn queue_airdrops(&self, airdrops: MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>>) {
+        for airdrop in airdrops {
+            let (address, amount) = airdrop.into_tuple();
+            self.pending_airdrops().push(&(address, amount));
+        }
+    }
+
+    /// OWNER RESTRICTED
+    ///
+    /// Mint and send the queued airdrops, stopping and persisting the cursor when
+    /// the remaining gas falls below `min_gas`. Re-entering while an operation is in
+    /// progress resumes from the persisted cursor rather than restarting; the cursor
+    /// and the queue are cleared once every airdrop has been distributed.
+    ///
+    #[only_owner]
+    #[endpoint(processAirdrops)]
+    fn process_airdrops(&self) {
+        let len = self.pending_airdrops().len();
+        let min_gas = self.min_gas().get();
+
+        // VecMapper is 1-indexed; a cleared cursor resumes from the first entry
+        let mut index = self.airdrop_cursor().get();
+        if index == 0 {
+            index = 1;
+        }
+
+        while index <= len {
+            let op = if self.blockchain().get_gas_left() < min_gas {
+                LoopOp::Break
+            } else {
+                let (address, queued_amount) = self.pending_airdrops().get(index);
+
+                // route batched mints through the same global-cap accounting as
+                // claim_airdrop, clamping to the remaining cap and tracking the total
+                let amount = self.clamp_to_global_cap(queued_amount);
+                if amount == 0u64 {
+                    // cap exhausted: stop without advancing so the mints already
+                    // performed in this call commit, and the queue resumes once the
+                    // cap is raised instead of reverting the whole transaction
+                    LoopOp::Break
+                } else {
+                    self.esdt_identifier().mint_and_send(&address, amount.clone());
+                    self.total_distributed().update(|val| *val += &amount);
+                    LoopOp::Continue
+                }
+            };
+
+            match op {
+                LoopOp::Continue => index += 1,
+                LoopOp::Break => {
+                    self.airdrop_cursor().set(index);
+                    return;
+                }
+            }
+        }
+
+        // queue exhausted: reset the subsystem for the next campaign
+        self.pending_airdrops().clear();
+        self.airdrop_cursor().clear();
+    }
+
+    /// OWNER RESTRICTED
+    ///
+    /// Set the gas floor used by `process_airdrops`.
+    ///
+    /// ### Arguments
+    ///
+    /// * **min_gas** - `u64` minimum remaining gas required to process one more airdrop
+    ///
+    #[only_owner]
+    #[endpoint(setMinGas)]
+    fn set_min_gas(&self, min_gas: u64) {
+        self.min_gas().set(min_gas);
     }
 
     // #################   functions    #################
 
+    /// Cap an amount to the configured per-claim limit, scaling the whole-token
+    /// limit by `10^num_decimals`. When the limit is `0` the amount is returned as-is.
+    ///
+    /// ### Arguments
+    ///
+    /// * **amount** - `BigUint` raw amount to cap
+    ///
+    fn cap_to_per_claim_limit(&self, amount: BigUint) -> BigUint {
+        let whole_limit = self.airdrop_limit().get();
+        if whole_limit == 0u64 {
+            return amount;
+        }
+
+        core::cmp::min(amount, self.to_raw_units(&whole_limit))
+    }
+
+    /// Clamp an amount to the raw units still available under the global cap.
+    /// When the cap is `0` the amount is returned unchanged.
+    ///
+    /// ### Arguments
+    ///
+    /// * **amount** - `BigUint` raw amount about to be distributed
+    ///
+    fn clamp_to_global_cap(&self, amount: BigUint) -> BigUint {
+        let cap_whole = self.global_airdrop_cap().get();
+        if cap_whole == 0u64 {
+            return amount;
+        }
+
+        let raw_cap = self.to_raw_units(&cap_whole);
+        let distributed = self.total_distributed().get();
+        let remaining = if raw_cap > distributed {
+            raw_cap - distributed
+        } else {
+            BigUint::zero()
+        };
+        core::cmp::min(amount, remaining)
+    }
+
+    /// Scale a whole-token amount to raw units using the stored `num_decimals`.
+    ///
+    /// ### Arguments
+    ///
+    /// * **whole_tokens** - `&BigUint` amount expressed in display units
+    ///
+    fn to_raw_units(&self, whole_tokens: &BigUint) -> BigUint {
+        whole_tokens * BigUint::from(10u64).pow(self.num_decimals().get() as u32)
+    }
+
     /// Require a payment to be made of the piggy token
     /// by checking its token identifier.
     ///
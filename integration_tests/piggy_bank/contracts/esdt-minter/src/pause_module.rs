@@ -0,0 +1,43 @@
+multiversx_sc::imports!();
+
+/// Owner-controlled pause facility.
+///
+/// State-changing endpoints call `require_not_paused()` at their top so operators
+/// can freeze the funds flow during maintenance or incidents without upgrading the
+/// contract.
+#[multiversx_sc::module]
+pub trait PauseModule {
+    // #################   storage    #################
+
+    /// Whether the contract is currently paused
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    // #################   endpoints    #################
+
+    /// OWNER RESTRICTED
+    ///
+    /// Pause the guarded endpoints.
+    #[only_owner]
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.paused().set(true);
+    }
+
+    /// OWNER RESTRICTED
+    ///
+    /// Resume the guarded endpoints.
+    #[only_owner]
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        self.paused().set(false);
+    }
+
+    // #################   functions    #################
+
+    /// Require the contract not to be paused.
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), "Contract is paused");
+    }
+}
@@ -8,4 +8,7 @@ pub trait EsdtMinterProxy {
     #[payable("*")]
     #[endpoint(claimInterests)]
     fn claim_interests(&self) -> EsdtTokenPayment<Self::Api>;
+
+    #[endpoint(fundInterest)]
+    fn fund_interest(&self, amount: BigUint) -> EsdtTokenPayment<Self::Api>;
 }
@@ -0,0 +1,216 @@
+#![no_std]
+
+mod esdt_minter_proxy;
+mod pause_module;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::contract]
+pub trait PiggyBank: pause_module::PauseModule {
+    // #################   proxy    #################
+
+    #[proxy]
+    fn esdt_minter_proxy(&self, sc_address: ManagedAddress) -> esdt_minter_proxy::Proxy<Self::Api>;
+
+    // #################   storage    #################
+
+    /// Token identifier for the token of the bank
+    #[view(getTokenIdentifier)]
+    #[storage_mapper("piggy_token_identifier")]
+    fn piggy_token_identifier(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Address of the esdt-minter contract for the piggy token
+    #[view(getEsdtMinnterAddress)]
+    #[storage_mapper("esdt_minter_address")]
+    fn esdt_minter_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Amount of token deposit per adress
+    #[view(getAddressAmount)]
+    #[storage_mapper("address_amount")]
+    fn address_amount(&self, address: ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Weighted-average deposit timestamp per address, used to measure how long
+    /// the funds have been sitting in the bank
+    #[view(getDepositTimestamp)]
+    #[storage_mapper("deposit_timestamp")]
+    fn deposit_timestamp(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Slope of the linear interest-rate curve
+    #[view(getInterestSlope)]
+    #[storage_mapper("interest_slope")]
+    fn interest_slope(&self) -> SingleValueMapper<BigUint>;
+
+    /// Intercept of the linear interest-rate curve
+    #[view(getInterestIntercept)]
+    #[storage_mapper("interest_intercept")]
+    fn interest_intercept(&self) -> SingleValueMapper<BigUint>;
+
+    /// Scaling divisor of the linear interest-rate curve
+    #[view(getInterestScale)]
+    #[storage_mapper("interest_scale")]
+    fn interest_scale(&self) -> SingleValueMapper<BigUint>;
+
+    // #################   views    #################
+
+    /// Preview the interest an address would earn if it withdrew right now, using
+    /// the linear curve `principal * (slope * elapsed_seconds + intercept) / SCALE`.
+    #[view(simulateInterest)]
+    fn simulate_interest(&self, address: ManagedAddress) -> BigUint {
+        let principal = self.address_amount(address.clone()).get();
+        if principal == 0u64 {
+            return BigUint::zero();
+        }
+
+        let elapsed = self.blockchain().get_block_timestamp() - self.deposit_timestamp(&address).get();
+        let rate = &self.interest_slope().get() * elapsed + self.interest_intercept().get();
+        principal * rate / self.interest_scale().get()
+    }
+
+    // #################   init && upgrade    #################
+    #[init]
+    fn init(&self, piggy_token_identifier: TokenIdentifier, esdt_minter_address: ManagedAddress) {
+        self.piggy_token_identifier()
+            .set_if_empty(piggy_token_identifier);
+        self.esdt_minter_address().set_if_empty(esdt_minter_address);
+        // default to a neutral scale so the curve never divides by zero
+        self.interest_scale().set_if_empty(BigUint::from(1u64));
+    }
+
+    // #################   owner config    #################
+
+    /// OWNER RESTRICTED
+    ///
+    /// Set the slope of the linear interest-rate curve.
+    #[only_owner]
+    #[endpoint(setInterestSlope)]
+    fn set_interest_slope(&self, slope: BigUint) {
+        self.interest_slope().set(slope);
+    }
+
+    /// OWNER RESTRICTED
+    ///
+    /// Set the intercept of the linear interest-rate curve.
+    #[only_owner]
+    #[endpoint(setInterestIntercept)]
+    fn set_interest_intercept(&self, intercept: BigUint) {
+        self.interest_intercept().set(intercept);
+    }
+
+    /// OWNER RESTRICTED
+    ///
+    /// Set the scaling divisor of the linear interest-rate curve.
+    #[only_owner]
+    #[endpoint(setInterestScale)]
+    fn set_interest_scale(&self, scale: BigUint) {
+        require!(scale > 0u64, "Scale must be non-zero");
+        self.interest_scale().set(scale);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    // #################   endpoints    #################
+
+    /// Allow a user to deposit some piggy tokens in the piggy bank.
+    ///
+    /// ### Payments
+    ///
+    /// * **deposit_payment** : Single payment of piggy token
+    ///
+    #[endpoint(deposit)]
+    #[payable("*")]
+    fn deposit(&self) {
+        self.require_not_paused();
+        let deposit_payment = self.call_value().single_esdt();
+        let caller = self.blockchain().get_caller();
+        self.require_good_token_identifier(&deposit_payment);
+
+        // weighted-average the deposit timestamp so topping up does not reset the
+        // whole accrual clock, but does not grant interest on the new funds either
+        let now = self.blockchain().get_block_timestamp();
+        let old_principal = self.address_amount(caller.clone()).get();
+        let new_timestamp = if old_principal == 0u64 {
+            now
+        } else {
+            let old_timestamp = self.deposit_timestamp(&caller).get();
+            (&old_principal * old_timestamp + &deposit_payment.amount * now)
+                / (&old_principal + &deposit_payment.amount)
+        };
+        self.deposit_timestamp(&caller)
+            .set(new_timestamp.to_u64().unwrap_or(now));
+
+        self.address_amount(caller)
+            .update(|val| *val += deposit_payment.amount);
+    }
+
+    /// Allow a user to withdraw all its piggy tokens from the piggy bank.
+    /// Interest will be issued and send along the principal.
+    ///
+    /// ### Return Payments
+    ///
+    /// * **withdraw_payment** : Single payment of piggy tokens containing all the user deposits and the interests earned
+    ///
+    #[endpoint(withdraw)]
+    #[payable("*")]
+    fn withdraw(&self) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        let available_amount = self.address_amount(caller.clone()).get();
+        require!(&available_amount > &BigUint::zero(), "Nothing to withdraw");
+
+        // compute the interest locally from the deposit duration
+        let interest = self.simulate_interest(caller.clone());
+
+        // ask the minter to fund (mint) exactly the computed interest
+        if interest > 0u64 {
+            self.call_fund_interest_sync(interest.clone());
+        }
+
+        // send the deposit along the interests back to the caller
+        let token_identifier = self.piggy_token_identifier().get();
+        self.send()
+            .direct_esdt(&caller, &token_identifier, 0u64, &(available_amount + interest));
+
+        // clear the deposit state of the caller
+        self.address_amount(caller.clone()).clear();
+        self.deposit_timestamp(&caller).clear();
+    }
+
+    // #################   restricted endpoints    #################
+
+    // #################   functions    #################
+
+    /// Sync call to the esdt-minter contract asking it to mint and send the
+    /// locally-computed interest amount to this contract.
+    ///
+    /// ### Arguments
+    ///
+    /// * **amount** - `BigUint` Interest amount to be minted by the esdt-minter
+    ///
+    /// ### Returns
+    ///
+    /// * **payment** - `EsdtTokenPayment<Self::Api>` minted interest payment recieved from the esdt-minter
+    ///
+    fn call_fund_interest_sync(&self, amount: BigUint) -> EsdtTokenPayment<Self::Api> {
+        let proxy_address = self.esdt_minter_address().get();
+        let mut proxy_instance = self.esdt_minter_proxy(proxy_address);
+
+        proxy_instance
+            .fund_interest(amount)
+            .execute_on_dest_context()
+    }
+
+    /// Require a payment to be made of the piggy token
+    /// by checking its token identifier.
+    ///
+    /// ### Arguments
+    ///
+    /// * **payment** - `&EsdtTokenPayment` payment to check
+    ///
+    fn require_good_token_identifier(&self, payment: &EsdtTokenPayment) {
+        require!(
+            payment.token_identifier == self.piggy_token_identifier().get(),
+            "Token identifier do not match the piggy token"
+        );
+    }
+}
@@ -1,32 +1,40 @@
-multiversx_sc::imports!();
-multiversx_sc::derive_imports!();
+use multiversx_sc::types::TestTokenIdentifier;
+use multiversx_sc_scenario::imports::{MxscPath, TestAddress, TestSCAddress};
+use multiversx_sc_scenario::ScenarioWorld;
 
-use multiversx_sc::types::Address;
-use multiversx_sc_scenario::{rust_biguint, testing_framework::BlockchainStateWrapper};
+pub const ESDT_MINTER_PATH: MxscPath = MxscPath::new("output/esdt-minter.mxsc.json");
+pub const PIGGY_BANK_PATH: MxscPath = MxscPath::new("output/piggy-bank.mxsc.json");
 
-pub const ESDT_MINTER_WASM_PATH: &'static str = "output/esdt-minter.wasm";
-pub const PIGGY_BANK_WASM_PATH: &'static str = "output/piggy-bank.wasm";
+pub const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+pub const USER_ADDRESS: TestAddress = TestAddress::new("user");
+pub const ESDT_MINTER_ADDRESS: TestSCAddress = TestSCAddress::new("esdt-minter");
+pub const PIGGY_BANK_ADDRESS: TestSCAddress = TestSCAddress::new("piggy-bank");
 
-pub const PIGGY_TOKEN_IDENTIFIER: &[u8] = b"PIGGY-cc4852";
+pub const PIGGY_TOKEN_IDENTIFIER: TestTokenIdentifier = TestTokenIdentifier::new("PIGGY-cc4852");
 pub const META_ESDT_NAME: &[u8] = b"Piggy Bank Token";
 
-pub const ESDT_MINTER_INTEREST_PERCENTAGE: u64 = 100; //100%
+pub const ESDT_MINTER_INTEREST_BPS: u64 = 10_000; //100%
 
+/// Register the contract code paths in a fresh [`ScenarioWorld`].
+pub fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.register_contract(ESDT_MINTER_PATH, esdt_minter::ContractBuilder);
+    blockchain.register_contract(PIGGY_BANK_PATH, piggy_bank::ContractBuilder);
+
+    blockchain
+}
+
+/// Holds the scenario world used across the test suite.
 pub struct BlockchainSetup {
-    pub blockchain_wrapper: BlockchainStateWrapper,
-    pub owner_address: Address,
-    pub user_address: Address,
+    pub world: ScenarioWorld,
 }
 
 pub fn create_blockchain_wrapper() -> BlockchainSetup {
-    let rust_zero = rust_biguint!(0u64);
-    let mut blockchain_wrapper = BlockchainStateWrapper::new();
-    let owner_address = blockchain_wrapper.create_user_account(&rust_zero);
-    let user_address = blockchain_wrapper.create_user_account(&rust_zero);
-
-    BlockchainSetup {
-        blockchain_wrapper,
-        owner_address,
-        user_address
-    }
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1);
+    world.account(USER_ADDRESS).nonce(1);
+
+    BlockchainSetup { world }
 }
@@ -1,160 +1,174 @@
-elrond_wasm::imports!();
-elrond_wasm::derive_imports!();
+use multiversx_sc::imports::EsdtLocalRole;
+use multiversx_sc::types::{BigUint, ManagedAddress, TestAddress};
+use multiversx_sc_scenario::api::StaticApi;
+use multiversx_sc_scenario::imports::ExpectError;
 
-use elrond_wasm_debug::{
-    managed_biguint, managed_token_id, rust_biguint, testing_framework::ContractObjWrapper,
-    tx_mock::TxResult, DebugApi,
-};
 use esdt_minter::EsdtMinter;
 
 use super::blockchain_mod::*;
 
-pub fn setup_esdt_minter<EsdtMinterObjBuilder>(
-    sc_builder: EsdtMinterObjBuilder,
-    blockchain_setup: &mut BlockchainSetup,
-) -> ContractObjWrapper<esdt_minter::ContractObj<DebugApi>, EsdtMinterObjBuilder>
-where
-    EsdtMinterObjBuilder: 'static + Copy + Fn() -> esdt_minter::ContractObj<DebugApi>,
-{
-    let blockchain_wrapper = &mut blockchain_setup.blockchain_wrapper;
-    let rust_zero = rust_biguint!(0u64);
+pub fn setup_esdt_minter(blockchain_setup: &mut BlockchainSetup) {
+    let world = &mut blockchain_setup.world;
 
-    let esdt_minter_wrapper = blockchain_wrapper.create_sc_account(
-        &rust_zero,
-        Some(&blockchain_setup.owner_address),
-        sc_builder,
-        PIGGY_BANK_WASM_PATH,
-    );
+    // register the contract account and give it the token roles
+    world
+        .account(ESDT_MINTER_ADDRESS)
+        .nonce(1)
+        .code(ESDT_MINTER_PATH)
+        .owner(OWNER_ADDRESS)
+        .esdt_roles(
+            PIGGY_TOKEN_IDENTIFIER,
+            vec![
+                EsdtLocalRole::Mint.name().to_string(),
+                EsdtLocalRole::Burn.name().to_string(),
+                EsdtLocalRole::NftCreate.name().to_string(),
+                EsdtLocalRole::NftAddQuantity.name().to_string(),
+            ],
+        );
 
     // deploy contract
-    blockchain_wrapper
-        .execute_tx(
-            &blockchain_setup.owner_address,
-            &esdt_minter_wrapper,
-            &rust_zero,
-            |sc| {
-                sc.init(ESDT_MINTER_INTEREST_PERCENTAGE);
-            },
-        )
-        .assert_ok();
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_MINTER_ADDRESS)
+        .whitebox(esdt_minter::contract_obj, |sc| {
+            sc.init(ESDT_MINTER_INTEREST_BPS);
+        });
 
     // fake token issuance
-
-    let token_roles = [
-        EsdtLocalRole::NftCreate,
-        EsdtLocalRole::NftAddQuantity,
-        EsdtLocalRole::NftBurn,
-        EsdtLocalRole::Mint,
-    ];
-
-    blockchain_wrapper.set_esdt_local_roles(
-        esdt_minter_wrapper.address_ref(),
-        PIGGY_TOKEN_IDENTIFIER,
-        &token_roles[..],
-    );
-
-    blockchain_wrapper
-        .execute_tx(
-            &blockchain_setup.owner_address,
-            &esdt_minter_wrapper,
-            &rust_zero,
-            |sc| {
-                let token_identifier = managed_token_id!(PIGGY_TOKEN_IDENTIFIER);
-                sc.esdt_identifier().set_token_id(token_identifier);
-            },
-        )
-        .assert_ok();
-
-    esdt_minter_wrapper
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_MINTER_ADDRESS)
+        .whitebox(esdt_minter::contract_obj, |sc| {
+            sc.esdt_identifier()
+                .set_token_id(PIGGY_TOKEN_IDENTIFIER.into());
+        });
 }
 
-pub fn add_airdrop_amount<EsdtMinterObjBuilder>(
+pub fn add_airdrop_amount(
     blockchain_setup: &mut BlockchainSetup,
-    esdt_minter_wrapper: &ContractObjWrapper<
-        esdt_minter::ContractObj<DebugApi>,
-        EsdtMinterObjBuilder,
-    >,
-    user_address: &Address,
+    user_address: TestAddress,
     airdrop_amount: u64,
-) -> TxResult
-where
-    EsdtMinterObjBuilder: 'static + Copy + Fn() -> esdt_minter::ContractObj<DebugApi>,
-{
-    blockchain_setup.blockchain_wrapper.execute_tx(
-        &blockchain_setup.owner_address,
-        &esdt_minter_wrapper,
-        &rust_biguint!(0u64),
-        |sc| {
+) {
+    blockchain_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_MINTER_ADDRESS)
+        .whitebox(esdt_minter::contract_obj, |sc| {
             sc.add_airdrop_amount(
-                ManagedAddress::from(user_address),
-                managed_biguint!(airdrop_amount),
+                ManagedAddress::from(user_address.eval_to_array()),
+                BigUint::<StaticApi>::from(airdrop_amount),
             );
-        },
-    )
+        });
 }
 
-pub fn add_interests_address<EsdtMinterObjBuilder>(
-    blockchain_setup: &mut BlockchainSetup,
-    esdt_minter_wrapper: &ContractObjWrapper<
-        esdt_minter::ContractObj<DebugApi>,
-        EsdtMinterObjBuilder,
-    >,
-    interests_address: &Address,
-) -> TxResult
-where
-    EsdtMinterObjBuilder: 'static + Copy + Fn() -> esdt_minter::ContractObj<DebugApi>,
-{
-    blockchain_setup.blockchain_wrapper.execute_tx(
-        &blockchain_setup.owner_address,
-        &esdt_minter_wrapper,
-        &rust_biguint!(0u64),
-        |sc| {
-            sc.add_interest_address(ManagedAddress::from(interests_address));
-        },
-    )
+pub fn add_interests_address(blockchain_setup: &mut BlockchainSetup, interests_address: TestAddress) {
+    blockchain_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_MINTER_ADDRESS)
+        .whitebox(esdt_minter::contract_obj, |sc| {
+            sc.add_interest_address(ManagedAddress::from(interests_address.eval_to_array()));
+        });
 }
 
-pub fn claim_airdrop<EsdtMinterObjBuilder>(
-    blockchain_setup: &mut BlockchainSetup,
-    esdt_minter_wrapper: &ContractObjWrapper<
-        esdt_minter::ContractObj<DebugApi>,
-        EsdtMinterObjBuilder,
-    >,
-    user_address: &Address,
-) -> TxResult
-where
-    EsdtMinterObjBuilder: 'static + Copy + Fn() -> esdt_minter::ContractObj<DebugApi>,
-{
-    blockchain_setup.blockchain_wrapper.execute_tx(
-        user_address,
-        &esdt_minter_wrapper,
-        &rust_biguint!(0u64),
-        |sc| {
+pub fn claim_airdrop(blockchain_setup: &mut BlockchainSetup, user_address: TestAddress) {
+    blockchain_setup
+        .world
+        .tx()
+        .from(user_address)
+        .to(ESDT_MINTER_ADDRESS)
+        .whitebox(esdt_minter::contract_obj, |sc| {
             sc.claim_airdrop();
-        },
-    )
+        });
+}
+
+// #################   set-state / check-state helpers    #################
+
+/// Write an airdrop balance directly into storage, skipping `add_airdrop_amount`.
+pub fn set_airdrop_amount<'a>(
+    blockchain_setup: &'a mut BlockchainSetup,
+    user_address: TestAddress,
+    amount: u64,
+) -> &'a mut BlockchainSetup {
+    blockchain_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_MINTER_ADDRESS)
+        .whitebox(esdt_minter::contract_obj, |sc| {
+            sc.airdrop_amount(ManagedAddress::from(user_address.eval_to_array()))
+                .set(BigUint::<StaticApi>::from(amount));
+        });
+    blockchain_setup
+}
+
+/// Write the minter token identifier directly into storage.
+pub fn set_esdt_identifier<'a>(
+    blockchain_setup: &'a mut BlockchainSetup,
+) -> &'a mut BlockchainSetup {
+    blockchain_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_MINTER_ADDRESS)
+        .whitebox(esdt_minter::contract_obj, |sc| {
+            sc.esdt_identifier()
+                .set_token_id(PIGGY_TOKEN_IDENTIFIER.into());
+        });
+    blockchain_setup
+}
+
+/// Assert an airdrop balance equals `expected`.
+pub fn check_airdrop_amount<'a>(
+    blockchain_setup: &'a mut BlockchainSetup,
+    user_address: TestAddress,
+    expected: u64,
+) -> &'a mut BlockchainSetup {
+    blockchain_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_MINTER_ADDRESS)
+        .whitebox(esdt_minter::contract_obj, |sc| {
+            assert_eq!(
+                sc.airdrop_amount(ManagedAddress::from(user_address.eval_to_array()))
+                    .get(),
+                BigUint::<StaticApi>::from(expected)
+            );
+        });
+    blockchain_setup
+}
+
+pub fn claim_interests(blockchain_setup: &mut BlockchainSetup, user_address: TestAddress, amount: u64) {
+    blockchain_setup
+        .world
+        .tx()
+        .from(user_address)
+        .to(ESDT_MINTER_ADDRESS)
+        .esdt(PIGGY_TOKEN_IDENTIFIER.with_amount(amount))
+        .whitebox(esdt_minter::contract_obj, |sc| {
+            sc.claim_interests();
+        });
 }
 
-pub fn claim_interests<EsdtMinterObjBuilder>(
+/// Claim interests expecting the call to be rejected for a non-whitelisted caller.
+pub fn claim_interests_expect_not_whitelisted(
     blockchain_setup: &mut BlockchainSetup,
-    esdt_minter_wrapper: &ContractObjWrapper<
-        esdt_minter::ContractObj<DebugApi>,
-        EsdtMinterObjBuilder,
-    >,
-    user_address: &Address,
+    user_address: TestAddress,
     amount: u64,
-) -> TxResult
-where
-    EsdtMinterObjBuilder: 'static + Copy + Fn() -> esdt_minter::ContractObj<DebugApi>,
-{
-    blockchain_setup.blockchain_wrapper.execute_esdt_transfer(
-        user_address,
-        &esdt_minter_wrapper,
-        PIGGY_TOKEN_IDENTIFIER,
-        0u64,
-        &rust_biguint!(amount),
-        |sc| {
+) {
+    blockchain_setup
+        .world
+        .tx()
+        .from(user_address)
+        .to(ESDT_MINTER_ADDRESS)
+        .esdt(PIGGY_TOKEN_IDENTIFIER.with_amount(amount))
+        .returns(ExpectError(4, "Item not whitelisted"))
+        .whitebox(esdt_minter::contract_obj, |sc| {
             sc.claim_interests();
-        },
-    )
+        });
 }
@@ -1,46 +1,26 @@
-use multiversx_sc_scenario::{rust_biguint, testing_framework::ContractObjWrapper, DebugApi};
-use esdt_minter::EsdtMinter;
+use multiversx_sc::types::ManagedAddress;
 
-multiversx_sc::imports!();
-multiversx_sc::derive_imports!();
+use esdt_minter::EsdtMinter;
 
 pub mod blockchain_mod;
 pub mod esdt_minter_mod;
 pub mod piggy_bank_mod;
 
-pub fn setup_contracts<EsdtMinterObjBuilder, PiggyBankObjBuilder>(
-    blockchain_setup: &mut blockchain_mod::BlockchainSetup,
-    esdt_minter_builder: EsdtMinterObjBuilder,
-    piggy_bank_builder: PiggyBankObjBuilder,
-) -> (
-    ContractObjWrapper<esdt_minter::ContractObj<DebugApi>, EsdtMinterObjBuilder>,
-    ContractObjWrapper<piggy_bank::ContractObj<DebugApi>, PiggyBankObjBuilder>,
-)
-where
-    EsdtMinterObjBuilder: 'static + Copy + Fn() -> esdt_minter::ContractObj<DebugApi>,
-    PiggyBankObjBuilder: 'static + Copy + Fn() -> piggy_bank::ContractObj<DebugApi>,
-{
-    let esdt_minter_wrapper =
-        esdt_minter_mod::setup_esdt_minter(esdt_minter_builder, blockchain_setup);
-    let piggy_bank_wrapper = piggy_bank_mod::setup_piggy_bank(
-        piggy_bank_builder,
-        blockchain_setup,
-        esdt_minter_wrapper.address_ref().clone(),
-    );
+use blockchain_mod::*;
 
-    // set piggy bank address in the esdt-minter
+/// Deploy both contracts in the scenario world and wire the piggy-bank address
+/// into the esdt-minter interest whitelist.
+pub fn setup_contracts(blockchain_setup: &mut BlockchainSetup) {
+    esdt_minter_mod::setup_esdt_minter(blockchain_setup);
+    piggy_bank_mod::setup_piggy_bank(blockchain_setup);
 
+    // set piggy bank address in the esdt-minter interest whitelist
     blockchain_setup
-        .blockchain_wrapper
-        .execute_tx(
-            &blockchain_setup.owner_address,
-            &esdt_minter_wrapper,
-            &rust_biguint!(0u64),
-            |sc| {
-                sc.add_interest_address(ManagedAddress::from(piggy_bank_wrapper.address_ref()));
-            },
-        )
-        .assert_ok();
-
-    (esdt_minter_wrapper, piggy_bank_wrapper)
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDT_MINTER_ADDRESS)
+        .whitebox(esdt_minter::contract_obj, |sc| {
+            sc.add_interest_address(ManagedAddress::from(PIGGY_BANK_ADDRESS.eval_to_array()));
+        });
 }
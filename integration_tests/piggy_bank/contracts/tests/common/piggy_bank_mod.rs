@@ -1,83 +1,110 @@
-elrond_wasm::imports!();
-elrond_wasm::derive_imports!();
+use multiversx_sc::types::{BigUint, ManagedAddress, TestAddress};
+use multiversx_sc_scenario::api::StaticApi;
 
-use elrond_wasm_debug::{
-    managed_token_id, rust_biguint, testing_framework::ContractObjWrapper, tx_mock::TxResult,
-    DebugApi,
-};
 use piggy_bank::PiggyBank;
 
 use super::blockchain_mod::*;
 
-pub fn setup_piggy_bank<PiggyBankObjBuilder>(
-    sc_builder: PiggyBankObjBuilder,
-    blockchain_setup: &mut BlockchainSetup,
-    esdt_minter_address: Address,
-) -> ContractObjWrapper<piggy_bank::ContractObj<DebugApi>, PiggyBankObjBuilder>
-where
-    PiggyBankObjBuilder: 'static + Copy + Fn() -> piggy_bank::ContractObj<DebugApi>,
-{
-    let blockchain_wrapper = &mut blockchain_setup.blockchain_wrapper;
-    let rust_zero = rust_biguint!(0u64);
-
-    let piggy_bank_wrapper = blockchain_wrapper.create_sc_account(
-        &rust_zero,
-        Some(&blockchain_setup.owner_address),
-        sc_builder,
-        ESDT_MINTER_WASM_PATH,
-    );
+pub fn setup_piggy_bank(blockchain_setup: &mut BlockchainSetup) {
+    let world = &mut blockchain_setup.world;
 
-    // deploy contract
-    blockchain_wrapper
-        .execute_tx(
-            &blockchain_setup.owner_address,
-            &piggy_bank_wrapper,
-            &rust_zero,
-            |sc| {
-                let token_identifier = managed_token_id!(PIGGY_TOKEN_IDENTIFIER);
-                sc.init(token_identifier, ManagedAddress::from(esdt_minter_address));
-            },
-        )
-        .assert_ok();
+    world
+        .account(PIGGY_BANK_ADDRESS)
+        .nonce(1)
+        .code(PIGGY_BANK_PATH)
+        .owner(OWNER_ADDRESS);
 
-    piggy_bank_wrapper
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PIGGY_BANK_ADDRESS)
+        .whitebox(piggy_bank::contract_obj, |sc| {
+            sc.init(
+                PIGGY_TOKEN_IDENTIFIER.into(),
+                ManagedAddress::from(ESDT_MINTER_ADDRESS.eval_to_array()),
+            );
+        });
 }
 
-pub fn user_deposit<PiggyBankObjBuilder>(
+/// Configure the linear interest-rate curve used by `withdraw`/`simulateInterest`.
+pub fn set_interest_curve(
     blockchain_setup: &mut BlockchainSetup,
-    piggy_bank_wrapper: &ContractObjWrapper<piggy_bank::ContractObj<DebugApi>, PiggyBankObjBuilder>,
-    user_address: &Address,
-    amount: u64,
-) -> TxResult
-where
-    PiggyBankObjBuilder: 'static + Copy + Fn() -> piggy_bank::ContractObj<DebugApi>,
-{
-    blockchain_setup.blockchain_wrapper.execute_esdt_transfer(
-        user_address,
-        &piggy_bank_wrapper,
-        PIGGY_TOKEN_IDENTIFIER,
-        0u64,
-        &rust_biguint!(amount),
-        |sc| {
+    slope: u64,
+    intercept: u64,
+    scale: u64,
+) {
+    blockchain_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PIGGY_BANK_ADDRESS)
+        .whitebox(piggy_bank::contract_obj, |sc| {
+            sc.set_interest_slope(BigUint::<StaticApi>::from(slope));
+            sc.set_interest_intercept(BigUint::<StaticApi>::from(intercept));
+            sc.set_interest_scale(BigUint::<StaticApi>::from(scale));
+        });
+}
+
+pub fn user_deposit(blockchain_setup: &mut BlockchainSetup, user_address: TestAddress, amount: u64) {
+    blockchain_setup
+        .world
+        .tx()
+        .from(user_address)
+        .to(PIGGY_BANK_ADDRESS)
+        .esdt(PIGGY_TOKEN_IDENTIFIER.with_amount(amount))
+        .whitebox(piggy_bank::contract_obj, |sc| {
             sc.deposit();
-        },
-    )
+        });
 }
 
-pub fn user_withdraw<PiggyBankObjBuilder>(
-    blockchain_setup: &mut BlockchainSetup,
-    piggy_bank_wrapper: &ContractObjWrapper<piggy_bank::ContractObj<DebugApi>, PiggyBankObjBuilder>,
-    user_address: &Address
-) -> TxResult
-where
-    PiggyBankObjBuilder: 'static + Copy + Fn() -> piggy_bank::ContractObj<DebugApi>,
-{
-    blockchain_setup.blockchain_wrapper.execute_tx(
-        user_address,
-        &piggy_bank_wrapper,
-        &rust_biguint!(0),
-        |sc| {
+// #################   set-state / check-state helpers    #################
+
+/// Write a deposit balance directly into storage, skipping a `deposit` tx.
+pub fn set_address_amount<'a>(
+    blockchain_setup: &'a mut BlockchainSetup,
+    user_address: TestAddress,
+    amount: u64,
+) -> &'a mut BlockchainSetup {
+    blockchain_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PIGGY_BANK_ADDRESS)
+        .whitebox(piggy_bank::contract_obj, |sc| {
+            sc.address_amount(ManagedAddress::from(user_address.eval_to_array()))
+                .set(BigUint::<StaticApi>::from(amount));
+        });
+    blockchain_setup
+}
+
+/// Assert a deposit balance equals `expected`.
+pub fn check_address_amount<'a>(
+    blockchain_setup: &'a mut BlockchainSetup,
+    user_address: TestAddress,
+    expected: u64,
+) -> &'a mut BlockchainSetup {
+    blockchain_setup
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PIGGY_BANK_ADDRESS)
+        .whitebox(piggy_bank::contract_obj, |sc| {
+            assert_eq!(
+                sc.address_amount(ManagedAddress::from(user_address.eval_to_array()))
+                    .get(),
+                BigUint::<StaticApi>::from(expected)
+            );
+        });
+    blockchain_setup
+}
+
+pub fn user_withdraw(blockchain_setup: &mut BlockchainSetup, user_address: TestAddress) {
+    blockchain_setup
+        .world
+        .tx()
+        .from(user_address)
+        .to(PIGGY_BANK_ADDRESS)
+        .whitebox(piggy_bank::contract_obj, |sc| {
             sc.withdraw();
-        },
-    )
-}
\ No newline at end of file
+        });
+}
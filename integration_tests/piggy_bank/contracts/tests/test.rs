@@ -1,128 +1,73 @@
-multiversx_sc::imports!();
-multiversx_sc::derive_imports!();
-
-use multiversx_sc_scenario::{rust_biguint, DebugApi};
+use multiversx_sc::types::BigUint;
 
 pub mod common;
 use crate::common::{blockchain_mod::*, setup_contracts};
 use common::{esdt_minter_mod::*, piggy_bank_mod::*};
 
+/// Assert that `address` holds exactly `amount` of the piggy token.
+fn check_esdt_balance(blockchain_setup: &mut BlockchainSetup, address: multiversx_sc::types::TestAddress, amount: u64) {
+    blockchain_setup
+        .world
+        .check_account(address)
+        .esdt_balance(PIGGY_TOKEN_IDENTIFIER, BigUint::from(amount));
+}
+
 #[test]
 fn test_correct_airdrop_claim() {
-    let _ = DebugApi::dummy();
-
     let mut blockchain_setup = create_blockchain_wrapper();
-    let (esdt_minter_wrapper, _) = setup_contracts(
-        &mut blockchain_setup,
-        esdt_minter::contract_obj,
-        piggy_bank::contract_obj,
-    );
+    setup_contracts(&mut blockchain_setup);
 
     // add some token amount to the user and make him claim
-    let airdrop_user = blockchain_setup.user_address.clone();
-    add_airdrop_amount(
-        &mut blockchain_setup,
-        &esdt_minter_wrapper,
-        &airdrop_user,
-        150_000,
-    )
-    .assert_ok();
-    claim_airdrop(&mut blockchain_setup, &esdt_minter_wrapper, &airdrop_user).assert_ok();
+    add_airdrop_amount(&mut blockchain_setup, USER_ADDRESS, 150_000);
+    claim_airdrop(&mut blockchain_setup, USER_ADDRESS);
 
     // check that the airdrop was sent correctly
-    blockchain_setup.blockchain_wrapper.check_esdt_balance(
-        &airdrop_user,
-        PIGGY_TOKEN_IDENTIFIER,
-        &rust_biguint!(150_000),
-    );
+    check_esdt_balance(&mut blockchain_setup, USER_ADDRESS, 150_000);
 }
 
 #[test]
 fn test_interests_whitelist() {
-    let _ = DebugApi::dummy();
-
     let mut blockchain_setup = create_blockchain_wrapper();
-    let esdt_minter_wrapper = setup_esdt_minter(esdt_minter::contract_obj, &mut blockchain_setup);
-
-    let interest_user = blockchain_setup.user_address.clone();
+    setup_esdt_minter(&mut blockchain_setup);
 
     // add some token amount to the user and make him claim (necessary to avoid esdt transfer error)
-    add_airdrop_amount(
-        &mut blockchain_setup,
-        &esdt_minter_wrapper,
-        &interest_user,
-        150_000,
-    )
-    .assert_ok();
-    claim_airdrop(&mut blockchain_setup, &esdt_minter_wrapper, &interest_user).assert_ok();
-
-    // assert user can not claim interests
-    claim_interests(
-        &mut blockchain_setup,
-        &esdt_minter_wrapper,
-        &interest_user,
-        0,
-    )
-    .assert_user_error("Item not whitelisted");
+    add_airdrop_amount(&mut blockchain_setup, USER_ADDRESS, 150_000);
+    claim_airdrop(&mut blockchain_setup, USER_ADDRESS);
+
+    // assert a non-whitelisted caller can not claim interests
+    claim_interests_expect_not_whitelisted(&mut blockchain_setup, USER_ADDRESS, 0);
 
     // add user to the interest whitelist
-    add_interests_address(&mut blockchain_setup, &esdt_minter_wrapper, &interest_user).assert_ok();
+    add_interests_address(&mut blockchain_setup, USER_ADDRESS);
 
     // assert user can claim interests
-    claim_interests(
-        &mut blockchain_setup,
-        &esdt_minter_wrapper,
-        &interest_user,
-        0,
-    )
-    .assert_ok();
+    claim_interests(&mut blockchain_setup, USER_ADDRESS, 0);
 }
 
 #[test]
 fn test_piggy_cycle() {
-    let _ = DebugApi::dummy();
-
     let mut blockchain_setup = create_blockchain_wrapper();
-    let (esdt_minter_wrapper, piggy_bank_wrapper) = setup_contracts(
-        &mut blockchain_setup,
-        esdt_minter::contract_obj,
-        piggy_bank::contract_obj,
-    );
+    setup_contracts(&mut blockchain_setup);
+
+    // curve yielding a 100% rate after 100 elapsed seconds: principal * (1*100 + 0) / 100
+    set_interest_curve(&mut blockchain_setup, 1, 0, 100);
 
     // add some token amount to the user and make him claim
-    let airdrop_user = blockchain_setup.user_address.clone();
-    add_airdrop_amount(
-        &mut blockchain_setup,
-        &esdt_minter_wrapper,
-        &airdrop_user,
-        150_000,
-    )
-    .assert_ok();
-    claim_airdrop(&mut blockchain_setup, &esdt_minter_wrapper, &airdrop_user).assert_ok();
-
-    // make the user deposit into the piggy bank
-    user_deposit(
-        &mut blockchain_setup,
-        &piggy_bank_wrapper,
-        &airdrop_user,
-        150_000,
-    )
-    .assert_ok();
+    add_airdrop_amount(&mut blockchain_setup, USER_ADDRESS, 150_000);
+    claim_airdrop(&mut blockchain_setup, USER_ADDRESS);
+
+    // make the user deposit into the piggy bank (deposit timestamp recorded at 0)
+    user_deposit(&mut blockchain_setup, USER_ADDRESS, 150_000);
 
     // assert user has no fund left
-    blockchain_setup.blockchain_wrapper.check_esdt_balance(
-        &airdrop_user,
-        PIGGY_TOKEN_IDENTIFIER,
-        &rust_biguint!(0),
-    );
+    check_esdt_balance(&mut blockchain_setup, USER_ADDRESS, 0);
+
+    // let the deposit accrue for 100 seconds before withdrawing
+    blockchain_setup.world.current_block().block_timestamp(100);
 
     // make the user withdraw from the piggy bank
-    user_withdraw(&mut blockchain_setup, &piggy_bank_wrapper, &airdrop_user).assert_ok();
-
-    // assert user has twice as much tokens as at the beginning
-    blockchain_setup.blockchain_wrapper.check_esdt_balance(
-        &airdrop_user,
-        PIGGY_TOKEN_IDENTIFIER,
-        &rust_biguint!(300_000),
-    );
+    user_withdraw(&mut blockchain_setup, USER_ADDRESS);
+
+    // assert user has twice as much tokens as at the beginning (principal + 100% interest)
+    check_esdt_balance(&mut blockchain_setup, USER_ADDRESS, 300_000);
 }
@@ -0,0 +1,154 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// A single hash-time-locked deposit held by the contract.
+///
+/// The funds are released to `beneficiary` by whoever reveals a preimage whose
+/// sha256 matches `secret_hash` before `timeout_block`, and returned to
+/// `depositor` once `timeout_block` is reached.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct Lock<M: ManagedTypeApi> {
+    pub payment: EsdtTokenPayment<M>,
+    pub secret_hash: ManagedBuffer<M>,
+    pub timeout_block: u64,
+    pub depositor: ManagedAddress<M>,
+    pub beneficiary: ManagedAddress<M>,
+}
+
+/// Hash-time-locked-contract escrow used to participate in cross-chain atomic swaps.
+///
+/// A lock is single-use: both `claim` and `refund` remove it from storage, so the
+/// funds can only ever leave the contract once. The timeout on this side must be
+/// set longer than the counterparty's side, otherwise the counterparty could claim
+/// here and still refund on the other chain.
+#[multiversx_sc::module]
+pub trait HtlcModule {
+    // #################   storage    #################
+
+    /// Incrementing id handed to the next created lock
+    #[view(getLastLockId)]
+    #[storage_mapper("last_lock_id")]
+    fn last_lock_id(&self) -> SingleValueMapper<u64>;
+
+    /// Active locks indexed by their generated id
+    #[storage_mapper("locks")]
+    fn locks(&self) -> MapMapper<u64, Lock<Self::Api>>;
+
+    // #################   endpoints    #################
+
+    /// Lock a single payment under a hash and a timeout so it can be claimed by
+    /// revealing the matching preimage, or refunded once the timeout is reached.
+    ///
+    /// ### Arguments
+    ///
+    /// * **secret_hash** - `ManagedBuffer`: sha256 of the secret shared between the two chains
+    /// * **timeout_block** - `u64`: block nonce from which on the lock can be refunded (and no longer claimed)
+    /// * **beneficiary** - `ManagedAddress`: address allowed to receive the funds on claim
+    ///
+    /// ### Returns
+    ///
+    /// * `u64`: id of the created lock
+    ///
+    #[payable("*")]
+    #[endpoint(lock)]
+    fn lock(
+        &self,
+        secret_hash: ManagedBuffer,
+        timeout_block: u64,
+        beneficiary: ManagedAddress,
+    ) -> u64 {
+        require!(
+            timeout_block > self.blockchain().get_block_nonce(),
+            "Timeout must be in the future"
+        );
+
+        let payment = self.call_value().single_esdt();
+        let depositor = self.blockchain().get_caller();
+
+        let lock_id = self.last_lock_id().get();
+        self.last_lock_id().set(lock_id + 1);
+
+        self.locks().insert(
+            lock_id,
+            Lock {
+                payment,
+                secret_hash,
+                timeout_block,
+                depositor,
+                beneficiary,
+            },
+        );
+
+        lock_id
+    }
+
+    /// Claim a lock by revealing the preimage of its hash. Revealing the preimage
+    /// on-chain lets the counterparty claim the mirror lock on the other chain with
+    /// the same secret.
+    ///
+    /// ### Arguments
+    ///
+    /// * **lock_id** - `u64`: id returned by `lock`
+    /// * **preimage** - `ManagedBuffer`: secret whose sha256 equals the stored hash
+    ///
+    #[endpoint(claim)]
+    fn claim(&self, lock_id: u64, preimage: ManagedBuffer) {
+        let lock = self.get_lock(lock_id);
+
+        require!(
+            self.blockchain().get_block_nonce() < lock.timeout_block,
+            "Lock has timed out"
+        );
+
+        // constant-length comparison: reject any preimage whose digest does not
+        // match the stored hash exactly, so padded inputs can not pass
+        let computed_hash = self.crypto().sha256(&preimage);
+        require!(
+            computed_hash.as_managed_buffer() == &lock.secret_hash,
+            "Invalid preimage"
+        );
+
+        self.locks().remove(&lock_id);
+
+        self.send().direct_esdt(
+            &lock.beneficiary,
+            &lock.payment.token_identifier,
+            lock.payment.token_nonce,
+            &lock.payment.amount,
+        );
+    }
+
+    /// Refund a lock to its original depositor once the timeout is reached.
+    ///
+    /// ### Arguments
+    ///
+    /// * **lock_id** - `u64`: id returned by `lock`
+    ///
+    #[endpoint(refund)]
+    fn refund(&self, lock_id: u64) {
+        let lock = self.get_lock(lock_id);
+
+        require!(
+            self.blockchain().get_block_nonce() >= lock.timeout_block,
+            "Lock has not timed out yet"
+        );
+
+        self.locks().remove(&lock_id);
+
+        self.send().direct_esdt(
+            &lock.depositor,
+            &lock.payment.token_identifier,
+            lock.payment.token_nonce,
+            &lock.payment.amount,
+        );
+    }
+
+    // #################   functions    #################
+
+    /// Fetch a lock by id, failing if it has already been claimed or refunded.
+    fn get_lock(&self, lock_id: u64) -> Lock<Self::Api> {
+        self.locks()
+            .get(&lock_id)
+            .unwrap_or_else(|| sc_panic!("Unknown lock"))
+    }
+}
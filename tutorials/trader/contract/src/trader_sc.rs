@@ -1,5 +1,6 @@
 #![no_std]
 
+mod htlc_module;
 mod jex_pair_proxy;
 
 #[allow(unused_imports)]
@@ -8,7 +9,7 @@ use multiversx_sc::imports::*;
 /// This contract acts as trader on the JEX/WEGLD pair from Jexchange
 /// A specified user (bot) will be able to trigger market buy or market sell for JEX
 #[multiversx_sc::contract]
-pub trait TraderSc {
+pub trait TraderSc: htlc_module::HtlcModule {
     // #################################
     //             storages
     // #################################
@@ -38,6 +39,30 @@ pub trait TraderSc {
     #[storage_mapper("executor_address")]
     fn executor_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Head of the rolling hashchain committing to the full ordered trade history.
+    /// The genesis head is the 32-zero buffer set in `init`.
+    ///
+    #[view(getTradeChainHash)]
+    #[storage_mapper("trade_chain_hash")]
+    fn trade_chain_hash(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    // #################################
+    //               events
+    // #################################
+
+    /// Emitted on each successful trade, carrying the new chain head and the raw
+    /// trade fields so a verifier can replay the events and recompute the chain.
+    #[event("trade")]
+    fn trade_event(
+        &self,
+        #[indexed] new_head: &ManagedBuffer,
+        #[indexed] is_buy: bool,
+        #[indexed] input_amount: &BigUint,
+        #[indexed] output_amount: &BigUint,
+        #[indexed] block_nonce: u64,
+        #[indexed] caller: &ManagedAddress,
+    );
+
     /// Initialize the contract with all the storage values
     #[init]
     fn init(
@@ -51,6 +76,10 @@ pub trait TraderSc {
         self.wegld_identifier().set(wegld_identifier);
         self.jex_pair_address().set(jex_pair_address);
         self.executor_address().set(executor_address);
+
+        // genesis head of the trade hashchain: 32 zero bytes
+        self.trade_chain_hash()
+            .set(ManagedBuffer::new_from_bytes(&[0u8; 32]));
     }
 
     /// nothing to do on upgrade
@@ -114,7 +143,12 @@ pub trait TraderSc {
             back_transfers.esdt_payments.len() == 1,
             "Expect to receive 1 back transfer"
         );
-        back_transfers.esdt_payments.get(0).amount
+        let output_amount = back_transfers.esdt_payments.get(0).amount;
+
+        // append the trade to the verifiable rolling hashchain
+        self.append_trade_to_chain(is_buy, &input_amount, &output_amount, &caller);
+
+        output_amount
     }
 
     // #################################
@@ -135,6 +169,58 @@ pub trait TraderSc {
             .direct_non_zero_esdt_payment(&self.blockchain().get_owner_address(), &payment);
     }
 
+    // #################################
+    //             functions
+    // #################################
+
+    /// Fold a trade into the rolling hashchain and emit the matching event.
+    ///
+    /// `new_head = keccak256(prev_head || is_buy_byte || len(input) || input_amount.to_bytes_be()
+    /// || len(output) || output_amount.to_bytes_be() || block_nonce.to_be_bytes() || caller)`
+    ///
+    /// Each variable-length amount is length-prefixed with its big-endian byte
+    /// length as a `u32` (4 big-endian bytes) so the boundary between the two
+    /// BigUints is unambiguous and can not be shifted to forge a colliding chain.
+    /// A verifier replaying the emitted events with the exact same field order and
+    /// encodings recomputes the same head, detecting any omitted or reordered trade.
+    ///
+    fn append_trade_to_chain(
+        &self,
+        is_buy: bool,
+        input_amount: &BigUint,
+        output_amount: &BigUint,
+        caller: &ManagedAddress,
+    ) {
+        let block_nonce = self.blockchain().get_block_nonce();
+
+        let mut preimage = self.trade_chain_hash().get();
+        preimage.append_bytes(&[u8::from(is_buy)]);
+        self.append_length_prefixed(&mut preimage, &input_amount.to_bytes_be_buffer());
+        self.append_length_prefixed(&mut preimage, &output_amount.to_bytes_be_buffer());
+        preimage.append_bytes(&block_nonce.to_be_bytes());
+        preimage.append(caller.as_managed_buffer());
+
+        let new_head = self.crypto().keccak256(&preimage);
+        let new_head_buffer = new_head.as_managed_buffer().clone();
+        self.trade_chain_hash().set(&new_head_buffer);
+
+        self.trade_event(
+            &new_head_buffer,
+            is_buy,
+            input_amount,
+            output_amount,
+            block_nonce,
+            caller,
+        );
+    }
+
+    /// Append `bytes` to `buffer`, prefixed by its byte length encoded as a
+    /// big-endian `u32`, so variable-length fields stay self-delimiting.
+    fn append_length_prefixed(&self, buffer: &mut ManagedBuffer, bytes: &ManagedBuffer) {
+        buffer.append_bytes(&(bytes.len() as u32).to_be_bytes());
+        buffer.append(bytes);
+    }
+
     // #################################
     //          owner endpoints
     // #################################